@@ -1,6 +1,7 @@
+use std::cell::RefCell;
 use std::fmt;
+use std::rc::Rc;
 
-use super::objclassmethod::PyClassMethod;
 use crate::common::borrow::BorrowValue;
 use crate::function::{PyFuncArgs, PyNativeFunc};
 use crate::obj::objstr::PyStrRef;
@@ -11,28 +12,301 @@ use crate::pyobject::{
 use crate::slots::{Callable, SlotDescriptor};
 use crate::vm::VirtualMachine;
 
+/// Mirrors CPython's `METH_VARARGS` vs. `METH_FASTCALL`: which shape of
+/// native function a [`PyFuncDef`] holds, and therefore how `Callable::call`
+/// should invoke it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CallingConvention {
+    /// The callee takes a fully materialized [`PyFuncArgs`].
+    Varargs,
+    /// The callee takes a borrowed positional-argument slice plus an
+    /// optional parallel slice of keyword-argument names, avoiding the
+    /// `PyFuncArgs` allocation for the common no/few-kwargs case.
+    Fastcall,
+}
+
+/// A native function using the fastcall calling convention: `args` holds
+/// all positional arguments followed by the values of any keyword
+/// arguments, and `kwnames` (when present) names that keyword tail in the
+/// same order, exactly as a contiguous stack slice would look to CPython's
+/// vectorcall. Invariant: `kwnames`, when `Some`, is never longer than
+/// `args` — it names a trailing subslice of it, not an independent list.
+/// Callers must uphold this; callees are entitled to assume it.
+pub type PyNativeFastcallFunc =
+    Box<dyn Fn(&VirtualMachine, &[PyObjectRef], Option<&[PyStrRef]>) -> PyResult + Send + Sync>;
+
+pub enum PyNativeFuncKind {
+    Varargs(PyNativeFunc),
+    Fastcall(PyNativeFastcallFunc),
+}
+
+impl PyNativeFuncKind {
+    pub fn convention(&self) -> CallingConvention {
+        match self {
+            PyNativeFuncKind::Varargs(_) => CallingConvention::Varargs,
+            PyNativeFuncKind::Fastcall(_) => CallingConvention::Fastcall,
+        }
+    }
+
+    /// Entry point for callers that already hold a materialized
+    /// `PyFuncArgs` (every call site in the tree today, since nothing yet
+    /// threads a borrowed stack slice all the way from `Frame` down to
+    /// here). This is a compatibility shim, not the allocation-avoiding
+    /// path itself — by the time `args` exists as a `PyFuncArgs` the
+    /// allocation this convention is meant to dodge has already happened.
+    /// Prefer `fastcall` directly when the caller has a slice in hand.
+    fn call(&self, vm: &VirtualMachine, args: PyFuncArgs) -> PyResult {
+        match self {
+            PyNativeFuncKind::Varargs(f) => f(vm, args),
+            PyNativeFuncKind::Fastcall(f) => {
+                let PyFuncArgs { mut args, kwargs } = args;
+                if kwargs.is_empty() {
+                    f(vm, &args, None)
+                } else {
+                    let mut kwnames = Vec::with_capacity(kwargs.len());
+                    for (name, value) in kwargs {
+                        kwnames.push(vm.ctx.new_stringref(name));
+                        args.push(value);
+                    }
+                    f(vm, &args, Some(&kwnames))
+                }
+            }
+        }
+    }
+
+    /// The actual allocation-avoiding entry point: call sites that hold a
+    /// borrowed positional-argument slice (e.g. a slice of the
+    /// interpreter's evaluation stack) reach a `Fastcall` callee directly,
+    /// without ever constructing a `PyFuncArgs`. Only the `Varargs` arm
+    /// falls back to materializing one, since that's the shape those
+    /// callees require.
+    fn fastcall(
+        &self,
+        vm: &VirtualMachine,
+        args: &[PyObjectRef],
+        kwnames: Option<&[PyStrRef]>,
+    ) -> PyResult {
+        match self {
+            PyNativeFuncKind::Fastcall(f) => f(vm, args, kwnames),
+            PyNativeFuncKind::Varargs(f) => f(vm, Self::slice_to_func_args(args, kwnames)),
+        }
+    }
+
+    /// `kwnames`, when present, names a *trailing subslice* of `args` (the
+    /// keyword-argument values), so it can never be longer than `args`
+    /// itself — that's the contract every `PyNativeFastcallFunc` caller
+    /// must uphold, mirroring CPython's vectorcall.
+    fn slice_to_func_args(args: &[PyObjectRef], kwnames: Option<&[PyStrRef]>) -> PyFuncArgs {
+        match kwnames {
+            None => PyFuncArgs {
+                args: args.to_vec(),
+                kwargs: Default::default(),
+            },
+            Some(kwnames) => {
+                debug_assert!(
+                    args.len() >= kwnames.len(),
+                    "kwnames must name a trailing subslice of args"
+                );
+                let split = args.len() - kwnames.len();
+                let (positional, kwvalues) = args.split_at(split);
+                let kwargs = kwnames
+                    .iter()
+                    .map(|name| name.borrow_value().to_owned())
+                    .zip(kwvalues.iter().cloned())
+                    .collect();
+                PyFuncArgs {
+                    args: positional.to_vec(),
+                    kwargs,
+                }
+            }
+        }
+    }
+}
+
+/// A `__name__`/`__doc__`-style string that starts out as cheap `&'static`
+/// data shared by every instance of a registered builtin, and is only
+/// promoted to a heap-allocated [`PyStrRef`] the first time Python code
+/// actually reads the property, at which point the interned value is
+/// cached so later reads are free.
+#[derive(Clone)]
+enum LazyPyStr {
+    Static(&'static str),
+    Interned(PyStrRef),
+}
+
+impl LazyPyStr {
+    fn as_str(&self) -> &str {
+        match self {
+            LazyPyStr::Static(s) => s,
+            LazyPyStr::Interned(s) => s.borrow_value(),
+        }
+    }
+
+    fn intern(cell: &RefCell<Self>, vm: &VirtualMachine) -> PyStrRef {
+        let mut cell = cell.borrow_mut();
+        match &*cell {
+            LazyPyStr::Interned(s) => s.clone(),
+            LazyPyStr::Static(s) => {
+                let interned = vm.ctx.new_stringref((*s).to_owned());
+                *cell = LazyPyStr::Interned(interned.clone());
+                interned
+            }
+        }
+    }
+}
+
 pub struct PyFuncDef {
-    pub func: PyNativeFunc,
-    pub name: Option<PyStrRef>,
-    pub doc: Option<PyStrRef>,
+    pub func: Rc<PyNativeFuncKind>,
+    name: RefCell<Option<LazyPyStr>>,
+    doc: Option<RefCell<LazyPyStr>>,
+    text_signature: Option<RefCell<LazyPyStr>>,
+    /// The `Owner` half of `Owner.name`-style `__qualname__`s for method
+    /// descriptors. `None` for free functions, whose `__qualname__` is just
+    /// their `__name__`.
+    owner_name: Option<PyStrRef>,
 }
 
 impl From<PyNativeFunc> for PyFuncDef {
     fn from(func: PyNativeFunc) -> Self {
         Self {
-            func,
-            name: None,
+            func: Rc::new(PyNativeFuncKind::Varargs(func)),
+            name: RefCell::new(None),
             doc: None,
+            text_signature: None,
+            owner_name: None,
         }
     }
 }
 
 impl PyFuncDef {
-    pub fn with_doc(mut self, doc: String, ctx: &PyContext) -> Self {
-        self.doc = Some(ctx.new_stringref(doc));
+    pub fn from_fastcall(func: PyNativeFastcallFunc) -> Self {
+        Self {
+            func: Rc::new(PyNativeFuncKind::Fastcall(func)),
+            name: RefCell::new(None),
+            doc: None,
+            text_signature: None,
+            owner_name: None,
+        }
+    }
+
+    pub fn with_name(mut self, name: &'static str) -> Self {
+        self.name = RefCell::new(Some(LazyPyStr::Static(name)));
         self
     }
 
+    pub fn with_owner_name(mut self, owner_name: PyStrRef) -> Self {
+        self.owner_name = Some(owner_name);
+        self
+    }
+
+    /// Registers a static docstring, splitting out a CPython
+    /// Argument-Clinic-style `__text_signature__` if present. Neither the
+    /// name nor the doc/signature text is allocated into a `PyStrRef` here;
+    /// that only happens lazily, the first time `__doc__`/`__text_signature__`
+    /// is actually read off the built object.
+    pub fn with_doc(mut self, doc: &'static str) -> Self {
+        let (text_signature, doc) = Self::split_text_signature(doc);
+        self.text_signature = text_signature.map(|sig| RefCell::new(LazyPyStr::Static(sig)));
+        self.doc = Some(RefCell::new(LazyPyStr::Static(doc)));
+        self
+    }
+
+    /// Splits a CPython Argument-Clinic-style docstring of the form
+    /// `"name(args...)\n--\n\ndoc..."` into its `__text_signature__` and
+    /// the remaining `__doc__`. The first line must look like a signature
+    /// (contain `(` and end with `)`) and must be *immediately* followed by
+    /// a line containing exactly `--` and a blank line; a `"--\n\n"`
+    /// appearing later in the docstring's prose (e.g. as a divider) does not
+    /// count. If that shape isn't found, there is no signature and `doc` is
+    /// returned unchanged. Since `doc` is `'static`, both halves of the
+    /// split remain `'static` too.
+    fn split_text_signature(doc: &'static str) -> (Option<&'static str>, &'static str) {
+        const AFTER_FIRST_LINE: &str = "--\n\n";
+        let first_line_end = match doc.find('\n') {
+            Some(idx) => idx,
+            None => return (None, doc),
+        };
+        let first_line = &doc[..first_line_end];
+        if !(first_line.contains('(') && first_line.trim_end().ends_with(')')) {
+            return (None, doc);
+        }
+        match doc[first_line_end + 1..].strip_prefix(AFTER_FIRST_LINE) {
+            Some(rest) => (Some(first_line), rest),
+            None => (None, doc),
+        }
+    }
+
+    fn name(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        let mut name = self.name.borrow_mut();
+        match name.as_ref()? {
+            LazyPyStr::Interned(s) => Some(s.clone()),
+            LazyPyStr::Static(s) => {
+                let interned = vm.ctx.new_stringref((*s).to_owned());
+                *name = Some(LazyPyStr::Interned(interned.clone()));
+                Some(interned)
+            }
+        }
+    }
+    fn set_name(&self, name: PyStrRef) {
+        *self.name.borrow_mut() = Some(LazyPyStr::Interned(name));
+    }
+    fn doc(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.doc.as_ref().map(|cell| LazyPyStr::intern(cell, vm))
+    }
+    fn text_signature(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.text_signature
+            .as_ref()
+            .map(|cell| LazyPyStr::intern(cell, vm))
+    }
+    /// `Owner.name` when an owner name was set with `with_owner_name` (as for
+    /// a method descriptor), otherwise just `name`; `build_method` does not
+    /// set one by itself (when there is no name at all, there is no qualname
+    /// either).
+    fn qualname(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        let name = self.name(vm)?;
+        Some(match &self.owner_name {
+            Some(owner) => {
+                vm.ctx
+                    .new_stringref(format!("{}.{}", owner.borrow_value(), name.borrow_value()))
+            }
+            None => name,
+        })
+    }
+
+    /// Binds this (unbound) method descriptor's underlying native function
+    /// to `receiver`, producing a `builtin_function_or_method`-shaped
+    /// [`PyBuiltinFunction`] whose `__self__` is `receiver`, matching
+    /// CPython's behavior for e.g. `{}.update` (a bound `method_descriptor`
+    /// becomes a `builtin_function_or_method`, not a distinct bound-method
+    /// type). The underlying native function is shared, not cloned.
+    ///
+    /// This clones the `name`/`doc`/`text_signature` cells as-is (a `Static`
+    /// variant stays `Static`, an already-`Interned` one is just a cheap
+    /// `PyStrRef` clone) rather than going through the eager `name(vm)` /
+    /// `doc(vm)` / `text_signature(vm)` accessors, so binding a method that's
+    /// never actually asked for its `__doc__` or `__text_signature__` doesn't
+    /// pay to intern them: laziness carries over from the unbound descriptor
+    /// to the bound one.
+    fn bind(&self, receiver: PyObjectRef, _vm: &VirtualMachine) -> PyBuiltinFunction {
+        PyBuiltinFunction {
+            value: PyFuncDef {
+                func: Rc::clone(&self.func),
+                name: RefCell::new(self.name.borrow().clone()),
+                doc: self
+                    .doc
+                    .as_ref()
+                    .map(|cell| RefCell::new(cell.borrow().clone())),
+                text_signature: self
+                    .text_signature
+                    .as_ref()
+                    .map(|cell| RefCell::new(cell.borrow().clone())),
+                owner_name: self.owner_name.clone(),
+            },
+            module: None,
+            zelf: Some(receiver),
+        }
+    }
+
     pub fn into_function(self) -> PyBuiltinFunction {
         self.into()
     }
@@ -47,10 +321,9 @@ impl PyFuncDef {
         )
     }
     pub fn build_classmethod(self, ctx: &PyContext) -> PyObjectRef {
-        // TODO: classmethod_descriptor
         PyObject::new(
-            PyClassMethod::from(self.build_method(ctx)),
-            ctx.types.classmethod_type.clone(),
+            PyBuiltinClassMethod::from(self),
+            ctx.types.classmethod_descriptor_type.clone(),
             None,
         )
     }
@@ -60,6 +333,10 @@ impl PyFuncDef {
 pub struct PyBuiltinFunction {
     value: PyFuncDef,
     module: Option<PyObjectRef>,
+    /// The receiver this function is bound to: the instance for a bound
+    /// method descriptor, or `None` for an ordinary free/module function
+    /// (whose `__self__` instead falls back to its `module`, like CPython).
+    zelf: Option<PyObjectRef>,
 }
 
 impl PyValue for PyBuiltinFunction {
@@ -70,11 +347,10 @@ impl PyValue for PyBuiltinFunction {
 
 impl fmt::Debug for PyBuiltinFunction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let name = match &self.value.name {
-            Some(s) => s.borrow_value(),
-            None => "<unknown name>",
-        };
-        write!(f, "builtin function {}", name)
+        match &*self.value.name.borrow() {
+            Some(name) => write!(f, "builtin function {}", name.as_str()),
+            None => write!(f, "builtin function <unknown name>"),
+        }
     }
 }
 
@@ -88,6 +364,7 @@ impl From<PyFuncDef> for PyBuiltinFunction {
         Self {
             value,
             module: None,
+            zelf: None,
         }
     }
 }
@@ -106,14 +383,31 @@ impl PyBuiltinFunction {
         )
     }
 
-    pub fn as_func(&self) -> &PyNativeFunc {
-        &self.value.func
+    pub fn as_func(&self) -> &PyNativeFuncKind {
+        &*self.value.func
+    }
+
+    /// Call this function from a borrowed argument slice, bypassing
+    /// `PyFuncArgs` entirely when it was registered with
+    /// `CallingConvention::Fastcall`. Hot native builtins (`len`,
+    /// `isinstance`, `getattr`, ...) should register via
+    /// `PyFuncDef::from_fastcall` and have their call sites reach them
+    /// through this method rather than `Callable::call` — wiring that up
+    /// end-to-end also means threading a slice through `Frame`'s call
+    /// handling, which lives outside this module.
+    pub fn fastcall(
+        &self,
+        vm: &VirtualMachine,
+        args: &[PyObjectRef],
+        kwnames: Option<&[PyStrRef]>,
+    ) -> PyResult {
+        self.value.func.fastcall(vm, args, kwnames)
     }
 }
 
 impl Callable for PyBuiltinFunction {
     fn call(zelf: &PyRef<Self>, args: PyFuncArgs, vm: &VirtualMachine) -> PyResult {
-        (zelf.value.func)(vm, args)
+        zelf.value.func.call(vm, args)
     }
 }
 
@@ -123,13 +417,29 @@ impl PyBuiltinFunction {
     fn module(&self, vm: &VirtualMachine) -> PyObjectRef {
         vm.unwrap_or_none(self.module.clone())
     }
+    #[pyproperty(name = "__self__")]
+    fn self_obj(&self, vm: &VirtualMachine) -> PyObjectRef {
+        vm.unwrap_or_none(self.zelf.clone().or_else(|| self.module.clone()))
+    }
+    #[pyproperty(magic)]
+    fn name(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.value.name(vm)
+    }
+    #[pyproperty(magic, setter)]
+    fn set_name(&self, name: PyStrRef) {
+        self.value.set_name(name)
+    }
     #[pyproperty(magic)]
-    fn name(&self) -> Option<PyStrRef> {
-        self.value.name.clone()
+    fn qualname(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.value.qualname(vm)
     }
     #[pyproperty(magic)]
-    fn doc(&self) -> Option<PyStrRef> {
-        self.value.doc.clone()
+    fn doc(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.value.doc(vm)
+    }
+    #[pyproperty(magic)]
+    fn text_signature(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.value.text_signature(vm)
     }
 }
 
@@ -160,15 +470,28 @@ impl PyBuiltinMethod {
     pub fn new_with_name(func: PyNativeFunc, name: PyStrRef) -> Self {
         Self {
             value: PyFuncDef {
-                func,
-                name: Some(name),
+                func: Rc::new(PyNativeFuncKind::Varargs(func)),
+                name: RefCell::new(Some(LazyPyStr::Interned(name))),
                 doc: None,
+                text_signature: None,
+                owner_name: None,
             },
         }
     }
 
-    pub fn as_func(&self) -> &PyNativeFunc {
-        &self.value.func
+    pub fn as_func(&self) -> &PyNativeFuncKind {
+        &*self.value.func
+    }
+
+    /// See `PyBuiltinFunction::fastcall`: calls through a borrowed argument
+    /// slice instead of a `PyFuncArgs`, for `Fastcall`-registered methods.
+    pub fn fastcall(
+        &self,
+        vm: &VirtualMachine,
+        args: &[PyObjectRef],
+        kwnames: Option<&[PyStrRef]>,
+    ) -> PyResult {
+        self.value.func.fastcall(vm, args, kwnames)
     }
 }
 
@@ -186,30 +509,153 @@ impl SlotDescriptor for PyBuiltinMethod {
         if vm.is_none(&obj) && !Self::_cls_is(&cls, &obj.class()) {
             Ok(zelf.into_object())
         } else {
-            Ok(vm.ctx.new_bound_method(zelf.into_object(), obj))
+            // Bound through an instance: like CPython, this becomes a
+            // `builtin_function_or_method` tracking `obj` as `__self__`,
+            // not a generic bound-method wrapper.
+            Ok(zelf.value.bind(obj, vm).build(&vm.ctx))
         }
     }
 }
 
 impl Callable for PyBuiltinMethod {
     fn call(zelf: &PyRef<Self>, args: PyFuncArgs, vm: &VirtualMachine) -> PyResult {
-        (zelf.value.func)(vm, args)
+        zelf.value.func.call(vm, args)
     }
 }
 
 #[pyimpl(with(SlotDescriptor, Callable))]
 impl PyBuiltinMethod {
     #[pyproperty(magic)]
-    fn name(&self) -> Option<PyStrRef> {
-        self.value.name.clone()
+    fn name(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.value.name(vm)
+    }
+    #[pyproperty(magic, setter)]
+    fn set_name(&self, name: PyStrRef) {
+        self.value.set_name(name)
+    }
+    #[pyproperty(magic)]
+    fn qualname(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.value.qualname(vm)
+    }
+    #[pyproperty(magic)]
+    fn doc(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.value.doc(vm)
+    }
+    #[pyproperty(magic)]
+    fn text_signature(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.value.text_signature(vm)
+    }
+}
+
+#[pyclass(module = false, name = "classmethod_descriptor")]
+pub struct PyBuiltinClassMethod {
+    value: PyFuncDef,
+}
+
+impl PyValue for PyBuiltinClassMethod {
+    fn class(vm: &VirtualMachine) -> PyTypeRef {
+        vm.ctx.types.classmethod_descriptor_type.clone()
+    }
+}
+
+impl fmt::Debug for PyBuiltinClassMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "classmethod descriptor")
+    }
+}
+
+impl From<PyFuncDef> for PyBuiltinClassMethod {
+    fn from(value: PyFuncDef) -> Self {
+        Self { value }
+    }
+}
+
+impl PyBuiltinClassMethod {
+    pub fn as_func(&self) -> &PyNativeFuncKind {
+        &*self.value.func
+    }
+}
+
+impl SlotDescriptor for PyBuiltinClassMethod {
+    fn descr_get(
+        zelf: PyObjectRef,
+        obj: Option<PyObjectRef>,
+        cls: Option<PyObjectRef>,
+        vm: &VirtualMachine,
+    ) -> PyResult {
+        let (zelf, obj) = match Self::_check(zelf, obj, vm) {
+            Ok(obj) => obj,
+            Err(result) => return result,
+        };
+        // Unlike a plain method descriptor, a classmethod_descriptor is
+        // always bound, and always to the class rather than the instance:
+        // accessed through an instance it binds to that instance's type,
+        // accessed through a type it binds to that type directly.
+        let bind_to = if vm.is_none(&obj) {
+            cls.unwrap_or_else(|| vm.ctx.none())
+        } else {
+            obj.class().into_object()
+        };
+        Ok(vm.ctx.new_bound_method(zelf.into_object(), bind_to))
+    }
+}
+
+impl Callable for PyBuiltinClassMethod {
+    fn call(zelf: &PyRef<Self>, args: PyFuncArgs, vm: &VirtualMachine) -> PyResult {
+        zelf.value.func.call(vm, args)
+    }
+}
+
+#[pyimpl(with(SlotDescriptor, Callable))]
+impl PyBuiltinClassMethod {
+    #[pyproperty(magic)]
+    fn name(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.value.name(vm)
     }
     #[pyproperty(magic)]
-    fn doc(&self) -> Option<PyStrRef> {
-        self.value.doc.clone()
+    fn doc(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.value.doc(vm)
+    }
+    #[pyproperty(magic)]
+    fn text_signature(&self, vm: &VirtualMachine) -> Option<PyStrRef> {
+        self.value.text_signature(vm)
     }
 }
 
 pub fn init(context: &PyContext) {
     PyBuiltinFunction::extend_class(context, &context.types.builtin_function_or_method_type);
     PyBuiltinMethod::extend_class(context, &context.types.method_descriptor_type);
+    PyBuiltinClassMethod::extend_class(context, &context.types.classmethod_descriptor_type);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PyFuncDef;
+
+    #[test]
+    fn split_text_signature_valid() {
+        let doc = "foo(x, y)\n--\n\nDoes a thing.";
+        assert_eq!(
+            PyFuncDef::split_text_signature(doc),
+            (Some("foo(x, y)"), "Does a thing.")
+        );
+    }
+
+    #[test]
+    fn split_text_signature_no_separator() {
+        let doc = "foo(x, y)\nDoes a thing, no separator at all.";
+        assert_eq!(PyFuncDef::split_text_signature(doc), (None, doc));
+    }
+
+    #[test]
+    fn split_text_signature_separator_later_in_prose() {
+        let doc = "foo(x)\nDoes a thing.\n\nExample:\n--\n\nmore text";
+        assert_eq!(PyFuncDef::split_text_signature(doc), (None, doc));
+    }
+
+    #[test]
+    fn split_text_signature_no_newline() {
+        let doc = "foo(x) is a one-line doc with no newline";
+        assert_eq!(PyFuncDef::split_text_signature(doc), (None, doc));
+    }
 }